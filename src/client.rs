@@ -4,11 +4,29 @@ use async_trait::async_trait;
 
 use anyhow::Result;
 
+use super::stream::RowStream;
+use super::transaction::Transaction;
 use super::{parse_query_result, QueryResult, Statement};
 
 pub struct ClientConfig {
     url: String,
     backend: String,
+    /// Remote sync URL a `replica` client pulls frames from.
+    replica_sync_url: Option<String>,
+    /// Auth token presented to the remote primary when syncing.
+    replica_auth_token: Option<String>,
+}
+
+impl ClientConfig {
+    /// Configures this client to run as an embedded replica: `url` is the
+    /// local file path, and `sync_url`/`auth_token` describe the remote
+    /// primary it syncs from.
+    pub fn with_replica(mut self, sync_url: String, auth_token: Option<String>) -> Self {
+        self.backend = "replica".to_string();
+        self.replica_sync_url = Some(sync_url);
+        self.replica_auth_token = auth_token;
+        self
+    }
 }
 
 /// Trait describing capabilities of a database client:
@@ -58,6 +76,52 @@ pub trait DatabaseClient {
         ret.pop();
         Ok(ret)
     }
+
+    /// Pins every subsequent request issued by this client to one
+    /// server-side session, until a matching [`unpin_session`](Self::unpin_session).
+    ///
+    /// Backends whose `batch()` is otherwise an independent stateless HTTP
+    /// call per invocation (the `reqwest` backends) override this to hold a
+    /// session/connection identifier and attach it to every request while
+    /// pinned, so a sequence of `BEGIN`/statements/`COMMIT` lands on the same
+    /// server-side transaction instead of unrelated ones. Backends with an
+    /// inherently persistent connection (e.g. `local`) can leave the default
+    /// no-op, since every call already shares that connection's state.
+    fn pin_session(&self) {}
+
+    /// Releases a session pinned by [`pin_session`](Self::pin_session).
+    fn unpin_session(&self) {}
+
+    /// Opens an interactive transaction, returning a [`Transaction`] guard
+    /// that lets the caller inspect intermediate `QueryResult`s and decide
+    /// whether to issue further statements before committing or rolling
+    /// back. Unlike [`DatabaseClient::transaction`], nesting is supported:
+    /// calling `begin()` again on the returned guard opens a `SAVEPOINT`
+    /// instead of a new `BEGIN`.
+    ///
+    /// For the stateless HTTP backends, every statement issued through the
+    /// returned guard must land on the same server-side transaction, so the
+    /// guard pins the client's session for as long as the outermost
+    /// transaction is open (see [`pin_session`](Self::pin_session)).
+    async fn begin(&self) -> Result<Transaction<'_, Self>>
+    where
+        Self: Sized,
+    {
+        Transaction::new(self, 0).await
+    }
+
+    /// Runs a single read statement and streams its rows back instead of
+    /// buffering the whole result set. Use this over `execute()` for large
+    /// result sets on memory-constrained edge/WASM hosts; for anything else,
+    /// `execute()`/`batch()` remain the default.
+    ///
+    /// The default implementation still buffers the full `QueryResult`
+    /// before replaying it as a stream. Backends able to parse their
+    /// response body incrementally should override this method.
+    async fn query_stream(&self, stmt: impl Into<Statement>) -> Result<RowStream> {
+        let result = self.execute(stmt).await?;
+        Ok(RowStream::from_buffered(result.rows))
+    }
 }
 
 /// A generic client struct, wrapping possible backends.
@@ -66,12 +130,16 @@ pub trait DatabaseClient {
 pub enum GenericClient {
     #[cfg(feature = "local_backend")]
     Local(super::local::Client),
-    #[cfg(feature = "reqwest_backend")]
-    Reqwest(super::reqwest::Client),
+    #[cfg(feature = "reqwest-native")]
+    Reqwest(super::reqwest::native::Client),
+    #[cfg(feature = "reqwest-wasm")]
+    ReqwestWasm(super::reqwest::wasm::Client),
     #[cfg(feature = "workers_backend")]
     Workers(super::workers::Client),
     #[cfg(feature = "spin_backend")]
     Spin(super::spin::Client),
+    #[cfg(feature = "replica_backend")]
+    Replica(super::replica::Client),
 }
 
 #[async_trait(?Send)]
@@ -83,12 +151,50 @@ impl DatabaseClient for GenericClient {
         match self {
             #[cfg(feature = "local_backend")]
             Self::Local(l) => l.batch(stmts).await,
-            #[cfg(feature = "reqwest_backend")]
+            #[cfg(feature = "reqwest-native")]
             Self::Reqwest(r) => r.batch(stmts).await,
+            #[cfg(feature = "reqwest-wasm")]
+            Self::ReqwestWasm(r) => r.batch(stmts).await,
             #[cfg(feature = "workers_backend")]
             Self::Workers(w) => w.batch(stmts).await,
             #[cfg(feature = "spin_backend")]
             Self::Spin(s) => s.batch(stmts).await,
+            #[cfg(feature = "replica_backend")]
+            Self::Replica(r) => r.batch(stmts).await,
+        }
+    }
+
+    fn pin_session(&self) {
+        match self {
+            #[cfg(feature = "local_backend")]
+            Self::Local(l) => l.pin_session(),
+            #[cfg(feature = "reqwest-native")]
+            Self::Reqwest(r) => r.pin_session(),
+            #[cfg(feature = "reqwest-wasm")]
+            Self::ReqwestWasm(r) => r.pin_session(),
+            #[cfg(feature = "workers_backend")]
+            Self::Workers(w) => w.pin_session(),
+            #[cfg(feature = "spin_backend")]
+            Self::Spin(s) => s.pin_session(),
+            #[cfg(feature = "replica_backend")]
+            Self::Replica(r) => r.pin_session(),
+        }
+    }
+
+    fn unpin_session(&self) {
+        match self {
+            #[cfg(feature = "local_backend")]
+            Self::Local(l) => l.unpin_session(),
+            #[cfg(feature = "reqwest-native")]
+            Self::Reqwest(r) => r.unpin_session(),
+            #[cfg(feature = "reqwest-wasm")]
+            Self::ReqwestWasm(r) => r.unpin_session(),
+            #[cfg(feature = "workers_backend")]
+            Self::Workers(w) => w.unpin_session(),
+            #[cfg(feature = "spin_backend")]
+            Self::Spin(s) => s.unpin_session(),
+            #[cfg(feature = "replica_backend")]
+            Self::Replica(r) => r.unpin_session(),
         }
     }
 }
@@ -115,7 +221,7 @@ pub fn new_client() -> anyhow::Result<GenericClient> {
     })?;
     let backend = std::env::var("LIBSQL_CLIENT_BACKEND").unwrap_or_else(|_| {
         if url.starts_with("http") {
-            return if cfg!(feature = "reqwest_backend") {
+            return if cfg!(any(feature = "reqwest-native", feature = "reqwest-wasm")) {
                 "reqwest"
             } else if cfg!(feature = "workers_backend") {
                 "workers"
@@ -130,7 +236,12 @@ pub fn new_client() -> anyhow::Result<GenericClient> {
         }
         .to_string()
     });
-    let config = ClientConfig { url, backend };
+    let config = ClientConfig {
+        url,
+        backend,
+        replica_sync_url: None,
+        replica_auth_token: None,
+    };
     new_client_with_config(&config)
 }
 
@@ -143,9 +254,13 @@ pub fn new_client_with_config(config: &ClientConfig) -> anyhow::Result<GenericCl
         "local" => {
             GenericClient::Local(super::local::Client::new(url)?)
         },
-        #[cfg(feature = "reqwest_backend")]
+        #[cfg(feature = "reqwest-native")]
         "reqwest" => {
-            GenericClient::Reqwest(super::reqwest::Client::from_url(&url::Url::parse(&url)?)?)
+            GenericClient::Reqwest(super::reqwest::native::Client::from_url(&url::Url::parse(&url)?)?)
+        },
+        #[cfg(all(feature = "reqwest-wasm", not(feature = "reqwest-native")))]
+        "reqwest" => {
+            GenericClient::ReqwestWasm(super::reqwest::wasm::Client::from_url(&url::Url::parse(&url)?)?)
         },
         #[cfg(feature = "workers_backend")]
         "workers" => {
@@ -155,6 +270,17 @@ pub fn new_client_with_config(config: &ClientConfig) -> anyhow::Result<GenericCl
         "spin" => {
             anyhow::bail!("Connecting from spin API may need access to specific Spin SDK secrets. Please call libsql_client::spin::Client::connect_from_url() directly")
         },
+        #[cfg(feature = "replica_backend")]
+        "replica" => {
+            let sync_url = config.replica_sync_url.clone().ok_or_else(|| {
+                anyhow::anyhow!("replica backend requires ClientConfig::replica_sync_url to be set")
+            })?;
+            GenericClient::Replica(super::replica::Client::new(super::replica::ReplicaConfig {
+                local_path: url,
+                sync_url,
+                auth_token: config.replica_auth_token.clone(),
+            })?)
+        },
         _ => anyhow::bail!("Unknown backend: {backend}. Make sure your backend exists and is enabled with its feature flag"),
     })
 }
@@ -0,0 +1,34 @@
+//! Client library for sqld and libSQL.
+
+mod client;
+mod migrate;
+mod queryresult;
+mod statement;
+mod stream;
+mod transaction;
+mod value;
+
+#[cfg(feature = "local_backend")]
+mod local;
+#[cfg(any(feature = "reqwest-native", feature = "reqwest-wasm"))]
+mod reqwest;
+#[cfg(feature = "replica_backend")]
+mod replica;
+#[cfg(feature = "spin_backend")]
+pub mod spin;
+#[cfg(feature = "workers_backend")]
+pub mod workers;
+
+pub use client::{new_client, new_client_with_config, ClientConfig, DatabaseClient, GenericClient};
+pub use migrate::{create_database, drop_database, migration_status, run_migrations, MigrationStatus};
+pub use queryresult::{QueryResult, Row};
+pub use statement::Statement;
+pub use stream::RowStream;
+pub use transaction::Transaction;
+pub use value::Value;
+
+#[cfg(feature = "replica_backend")]
+pub use replica::ReplicaConfig;
+
+pub(crate) use client::{json_to_query_result, statements_to_string};
+pub(crate) use queryresult::parse_query_result;
@@ -0,0 +1,86 @@
+//! Local, file-backed client talking directly to a SQLite file via
+//! `rusqlite` (vendored under the `local_backend` feature as
+//! `libsql_rusqlite`). Every call shares the same persistent connection, so
+//! unlike the HTTP backends there's no session to pin.
+
+use async_trait::async_trait;
+
+use anyhow::Result;
+
+use super::{DatabaseClient, QueryResult, Row, Statement, Value};
+
+/// A client backed by a local SQLite file (or `:memory:`).
+pub struct Client {
+    conn: libsql_rusqlite::Connection,
+}
+
+impl Client {
+    /// Opens `path`, creating the file if it doesn't exist yet. A leading
+    /// `file://` scheme, as produced by `LIBSQL_CLIENT_URL`, is stripped
+    /// since `rusqlite` expects a plain filesystem path.
+    pub fn new(path: impl AsRef<str>) -> Result<Self> {
+        let path = path.as_ref();
+        let path = path.strip_prefix("file://").unwrap_or(path);
+        Ok(Self {
+            conn: libsql_rusqlite::Connection::open(path)?,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl DatabaseClient for Client {
+    async fn batch(
+        &self,
+        stmts: impl IntoIterator<Item = impl Into<Statement>>,
+    ) -> Result<Vec<QueryResult>> {
+        let mut results = Vec::new();
+        for stmt in stmts {
+            let stmt = stmt.into();
+            let mut prepared = self.conn.prepare(&stmt.sql)?;
+            let columns: Vec<String> = prepared
+                .column_names()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            let params: Vec<libsql_rusqlite::types::Value> =
+                stmt.params.into_iter().map(Into::into).collect();
+
+            let mut rows = Vec::new();
+            let mut rows_iter = prepared.query(libsql_rusqlite::params_from_iter(params))?;
+            while let Some(row) = rows_iter.next()? {
+                let mut values = Vec::with_capacity(columns.len());
+                for i in 0..columns.len() {
+                    let value: libsql_rusqlite::types::Value = row.get(i)?;
+                    values.push(value.into());
+                }
+                rows.push(Row { values });
+            }
+            results.push(QueryResult { columns, rows });
+        }
+        Ok(results)
+    }
+}
+
+impl From<Value> for libsql_rusqlite::types::Value {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => libsql_rusqlite::types::Value::Null,
+            Value::Integer(i) => libsql_rusqlite::types::Value::Integer(i),
+            Value::Real(r) => libsql_rusqlite::types::Value::Real(r),
+            Value::Text(s) => libsql_rusqlite::types::Value::Text(s),
+            Value::Blob(b) => libsql_rusqlite::types::Value::Blob(b),
+        }
+    }
+}
+
+impl From<libsql_rusqlite::types::Value> for Value {
+    fn from(value: libsql_rusqlite::types::Value) -> Self {
+        match value {
+            libsql_rusqlite::types::Value::Null => Value::Null,
+            libsql_rusqlite::types::Value::Integer(i) => Value::Integer(i),
+            libsql_rusqlite::types::Value::Real(r) => Value::Real(r),
+            libsql_rusqlite::types::Value::Text(s) => Value::Text(s),
+            libsql_rusqlite::types::Value::Blob(b) => Value::Blob(b),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Database lifecycle and migration helpers, ported from the create/drop/setup
+//! workflow in SQLx's CLI and layered entirely on [`DatabaseClient`].
+
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use super::{DatabaseClient, Statement, Value};
+
+const MIGRATIONS_TABLE: &str = "_migrations";
+
+/// Creates the target database, if the backend supports it.
+///
+/// For `sqld`/libSQL servers the database is expected to already exist, so
+/// this only issues `CREATE TABLE IF NOT EXISTS _migrations`, which confirms
+/// connectivity and makes sure the bookkeeping table that
+/// [`run_migrations`] and [`migration_status`] read from exists; for local
+/// file-backed clients, opening the
+/// connection itself creates the file, so this is a formality that keeps the
+/// `create_database`/`drop_database`/`run_migrations` trio symmetric.
+pub async fn create_database(client: &impl DatabaseClient) -> Result<()> {
+    client
+        .execute(format!(
+            "CREATE TABLE IF NOT EXISTS {MIGRATIONS_TABLE} (version INTEGER PRIMARY KEY, name TEXT NOT NULL)"
+        ))
+        .await?;
+    Ok(())
+}
+
+/// Drops every user table in the database, including the `_migrations`
+/// bookkeeping table - the counterpart to [`create_database`], for wiping a
+/// database back to empty so the next `run_migrations()` starts from
+/// scratch.
+///
+/// # Arguments
+/// * `client` - the database to drop every table from
+/// * `confirm` - must be explicitly set to `true`; with `false` the call
+///   returns an error without issuing any statement, as a safeguard against
+///   wiping the whole database by accident
+pub async fn drop_database(client: &impl DatabaseClient, confirm: bool) -> Result<()> {
+    if !confirm {
+        bail!("drop_database() requires confirm = true to avoid accidental data loss");
+    }
+    let tables = client
+        .execute("SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'")
+        .await?;
+    for row in &tables.rows {
+        let name = row.values[0].to_string();
+        client.execute(format!("DROP TABLE IF EXISTS {name}")).await?;
+    }
+    Ok(())
+}
+
+/// The state of a single migration file discovered on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub name: String,
+    pub applied: bool,
+}
+
+/// Reads ordered `*.sql` files from `dir` (named `<version>_<name>.sql`, e.g.
+/// `0001_create_users.sql`) and applies the ones not yet recorded in the
+/// `_migrations` table, each inside its own transaction.
+pub async fn run_migrations(client: &impl DatabaseClient, dir: impl AsRef<Path>) -> Result<()> {
+    create_database(client).await?;
+    let applied = applied_versions(client).await?;
+
+    for (version, name, path) in discover_migrations(dir.as_ref())? {
+        if applied.contains(&version) {
+            continue;
+        }
+        let sql = std::fs::read_to_string(&path)?;
+        let tx = client.begin().await?;
+        tx.execute(sql).await?;
+        tx.execute(Statement::with_params(
+            format!("INSERT INTO {MIGRATIONS_TABLE} (version, name) VALUES (?, ?)"),
+            vec![Value::Integer(version), Value::Text(name.clone())],
+        ))
+        .await?;
+        tx.commit().await?;
+    }
+    Ok(())
+}
+
+/// Reports which discovered migrations are applied vs pending, in order.
+pub async fn migration_status(
+    client: &impl DatabaseClient,
+    dir: impl AsRef<Path>,
+) -> Result<Vec<MigrationStatus>> {
+    create_database(client).await?;
+    let applied = applied_versions(client).await?;
+
+    Ok(discover_migrations(dir.as_ref())?
+        .into_iter()
+        .map(|(version, name, _path)| MigrationStatus {
+            applied: applied.contains(&version),
+            version,
+            name,
+        })
+        .collect())
+}
+
+async fn applied_versions(client: &impl DatabaseClient) -> Result<std::collections::HashSet<i64>> {
+    let result = client
+        .execute(format!("SELECT version FROM {MIGRATIONS_TABLE}"))
+        .await?;
+    Ok(result
+        .rows
+        .iter()
+        .filter_map(|row| row.values.first())
+        .filter_map(|value| value.to_string().parse::<i64>().ok())
+        .collect())
+}
+
+/// Lists `<version>_<name>.sql` files in `dir`, sorted by version.
+fn discover_migrations(dir: &Path) -> Result<Vec<(i64, String, std::path::PathBuf)>> {
+    let mut migrations = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("sql") {
+            continue;
+        }
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow::anyhow!("invalid migration filename: {}", path.display()))?;
+        let (version, name) = stem
+            .split_once('_')
+            .ok_or_else(|| anyhow::anyhow!("migration filename must be `<version>_<name>.sql`: {}", path.display()))?;
+        let version: i64 = version
+            .parse()
+            .map_err(|_| anyhow::anyhow!("migration version must be numeric: {}", path.display()))?;
+        migrations.push((version, name.to_string(), path));
+    }
+    migrations.sort_by_key(|(version, ..)| *version);
+    Ok(migrations)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{QueryResult, Row};
+
+    #[test]
+    fn discover_migrations_sorts_by_version_and_parses_name() {
+        let dir = std::env::temp_dir().join(format!(
+            "libsql_client_migrate_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0002_add_posts.sql"), "-- noop").unwrap();
+        std::fs::write(dir.join("0001_create_users.sql"), "-- noop").unwrap();
+        std::fs::write(dir.join("not_a_migration.txt"), "ignored").unwrap();
+
+        let migrations = discover_migrations(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(migrations.len(), 2);
+        assert_eq!(migrations[0].0, 1);
+        assert_eq!(migrations[0].1, "create_users");
+        assert_eq!(migrations[1].0, 2);
+        assert_eq!(migrations[1].1, "add_posts");
+    }
+
+    #[derive(Default)]
+    struct MockClient {
+        issued: RefCell<Vec<String>>,
+    }
+
+    #[async_trait(?Send)]
+    impl DatabaseClient for MockClient {
+        async fn batch(
+            &self,
+            stmts: impl IntoIterator<Item = impl Into<Statement>>,
+        ) -> Result<Vec<QueryResult>> {
+            let mut results = Vec::new();
+            for stmt in stmts {
+                let stmt = stmt.into();
+                self.issued.borrow_mut().push(stmt.sql.clone());
+                if stmt.sql.starts_with("SELECT name FROM sqlite_master") {
+                    results.push(QueryResult {
+                        columns: vec!["name".to_string()],
+                        rows: vec![
+                            Row {
+                                values: vec![Value::Text("users".to_string())],
+                            },
+                            Row {
+                                values: vec![Value::Text(MIGRATIONS_TABLE.to_string())],
+                            },
+                        ],
+                    });
+                } else {
+                    results.push(QueryResult::default());
+                }
+            }
+            Ok(results)
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_database_without_confirm_errors() {
+        let client = MockClient::default();
+        assert!(drop_database(&client, false).await.is_err());
+        assert!(client.issued.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    async fn drop_database_drops_every_user_table_and_the_migrations_table() {
+        let client = MockClient::default();
+        drop_database(&client, true).await.unwrap();
+        let issued = client.issued.borrow();
+        assert!(issued.iter().any(|s| s.contains("DROP TABLE IF EXISTS users")));
+        assert!(issued
+            .iter()
+            .any(|s| s.contains(&format!("DROP TABLE IF EXISTS {MIGRATIONS_TABLE}"))));
+    }
+}
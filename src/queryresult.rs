@@ -0,0 +1,49 @@
+//! The result of executing a single SQL statement.
+
+use serde::{Deserialize, Serialize};
+
+use super::Value;
+
+/// One row of a [`QueryResult`], in the same order as its `columns`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Row {
+    pub values: Vec<Value>,
+}
+
+/// The outcome of running one SQL statement.
+#[derive(Debug, Clone, Default)]
+pub struct QueryResult {
+    pub columns: Vec<String>,
+    pub rows: Vec<Row>,
+}
+
+/// Parses the `{"columns": [...], "rows": [...]}` object a `sqld`/libSQL
+/// endpoint returns for the statement at `idx` in a batch, `idx` only being
+/// used to point at which statement failed to parse.
+pub(crate) fn parse_query_result(value: serde_json::Value, idx: usize) -> Result<QueryResult, String> {
+    let obj = value
+        .as_object()
+        .ok_or_else(|| format!("statement {idx}: expected a JSON object, got {value}"))?;
+
+    let columns = obj
+        .get("columns")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let rows = obj
+        .get("rows")
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|row| serde_json::from_value(row).map_err(|e| format!("statement {idx}: {e}")))
+        .collect::<Result<Vec<Row>, String>>()?;
+
+    Ok(QueryResult { columns, rows })
+}
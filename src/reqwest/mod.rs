@@ -0,0 +1,12 @@
+//! HTTP backend powered by `reqwest`. [`native`] and [`wasm`] are kept as
+//! separate modules, each gated by its own feature flag (`reqwest-native` /
+//! `reqwest-wasm`) so a pure-Rust build can pick either target without
+//! pulling in the other, but both just re-export [`shared::Client`] - see
+//! that module for why there's no real native-vs-wasm divergence here.
+
+mod shared;
+
+#[cfg(feature = "reqwest-native")]
+pub mod native;
+#[cfg(feature = "reqwest-wasm")]
+pub mod wasm;
@@ -0,0 +1,5 @@
+//! Native `reqwest` backend, talking to a remote `sqld`/libSQL HTTP endpoint
+//! over a regular non-WASM async HTTP client. There's nothing native-specific
+//! about the implementation - see [`super::shared`].
+
+pub use super::shared::Client;
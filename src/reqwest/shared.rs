@@ -0,0 +1,109 @@
+//! The single `reqwest`-backed client implementation behind both
+//! [`super::native`] and [`super::wasm`] - `reqwest` itself already swaps in
+//! a `fetch`-based transport when built for `wasm32-unknown-unknown`, so
+//! there's no native-vs-wasm behavior left for this crate to diverge on.
+
+use std::cell::{Cell, RefCell};
+
+use async_trait::async_trait;
+
+use anyhow::Result;
+
+use futures::StreamExt;
+
+use crate::stream::{parse_rows_stream, RowStream};
+use crate::{json_to_query_result, statements_to_string, DatabaseClient, QueryResult, Row, Statement};
+
+const SESSION_HEADER: &str = "x-libsql-session-id";
+
+/// A libSQL client backed by a `reqwest::Client` (its `fetch`-based
+/// transport when built for `wasm32-unknown-unknown`).
+///
+/// Each `batch()` call is its own independent HTTP request; while a
+/// [`crate::Transaction`] is open, [`pin_session`](DatabaseClient::pin_session)
+/// keeps every request on the same server-side session by round-tripping a
+/// `x-libsql-session-id` header the server assigns on the first pinned
+/// request.
+pub struct Client {
+    inner: ::reqwest::Client,
+    url: url::Url,
+    auth_token: Option<String>,
+    pinned: Cell<bool>,
+    session_id: RefCell<Option<String>>,
+}
+
+impl Client {
+    /// Creates a client from a `http(s)://` URL. Credentials embedded in the
+    /// URL's userinfo, if any, are not used for authentication - call
+    /// [`Client::set_auth_token`] instead.
+    pub fn from_url(url: &url::Url) -> Result<Self> {
+        Ok(Self {
+            inner: ::reqwest::Client::new(),
+            url: url.clone(),
+            auth_token: None,
+            pinned: Cell::new(false),
+            session_id: RefCell::new(None),
+        })
+    }
+
+    /// Sets the bearer token presented as `Authorization: Bearer <token>`.
+    pub fn set_auth_token(&mut self, auth_token: String) {
+        self.auth_token = Some(auth_token);
+    }
+}
+
+#[async_trait(?Send)]
+impl DatabaseClient for Client {
+    async fn batch(
+        &self,
+        stmts: impl IntoIterator<Item = impl Into<Statement>>,
+    ) -> Result<Vec<QueryResult>> {
+        let (body, stmts_count) = statements_to_string(stmts);
+        let mut request = self.inner.post(self.url.clone()).body(body);
+        if let Some(token) = &self.auth_token {
+            request = request.bearer_auth(token);
+        }
+        if self.pinned.get() {
+            if let Some(session_id) = self.session_id.borrow().as_ref() {
+                request = request.header(SESSION_HEADER, session_id.clone());
+            }
+        }
+        let response = request.send().await?.error_for_status()?;
+        if self.pinned.get() {
+            if let Some(session_id) = response.headers().get(SESSION_HEADER) {
+                *self.session_id.borrow_mut() = Some(session_id.to_str()?.to_string());
+            }
+        }
+        let response_json: serde_json::Value = response.json().await?;
+        json_to_query_result(response_json, stmts_count)
+    }
+
+    fn pin_session(&self) {
+        self.pinned.set(true);
+    }
+
+    fn unpin_session(&self) {
+        self.pinned.set(false);
+        *self.session_id.borrow_mut() = None;
+    }
+
+    /// Streams rows as they're parsed off the wire instead of buffering the
+    /// whole `QueryResult`, by reading the response body as it arrives and
+    /// incrementally parsing elements of its `"rows"` array.
+    async fn query_stream(&self, stmt: impl Into<Statement>) -> Result<RowStream> {
+        let (body, _) = statements_to_string(std::iter::once(stmt));
+        let mut request = self.inner.post(self.url.clone()).body(body);
+        if let Some(token) = &self.auth_token {
+            request = request.bearer_auth(token);
+        }
+        if self.pinned.get() {
+            if let Some(session_id) = self.session_id.borrow().as_ref() {
+                request = request.header(SESSION_HEADER, session_id.clone());
+            }
+        }
+        let response = request.send().await?.error_for_status()?;
+        let rows = parse_rows_stream(response.bytes_stream())
+            .map(|value| Ok(serde_json::from_value::<Row>(value?)?));
+        Ok(RowStream::new(rows))
+    }
+}
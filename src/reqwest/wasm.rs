@@ -0,0 +1,7 @@
+//! `reqwest` backend compiled for `wasm32-unknown-unknown`, routed through
+//! the browser/edge `fetch` API rather than a native transport. `reqwest`
+//! swaps in its `fetch`-based client automatically when built for this
+//! target, so the implementation is identical to [`super::native`] - see
+//! [`super::shared`].
+
+pub use super::shared::Client;
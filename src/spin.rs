@@ -0,0 +1,46 @@
+//! Fermyon Spin backend. Spin components read their configuration through
+//! SDK-managed secrets rather than `std::env`, so connecting needs the URL
+//! passed in explicitly - see [`Client::connect_from_url`].
+
+use async_trait::async_trait;
+
+use anyhow::Result;
+
+use super::{DatabaseClient, QueryResult, Statement};
+
+/// A client for use inside a Spin component, backed by the native `reqwest`
+/// client under the hood (Spin's outbound HTTP is a native, non-WASM-fetch
+/// transport).
+pub struct Client {
+    inner: super::reqwest::native::Client,
+}
+
+impl Client {
+    /// Builds a client from a `sync_url` (typically read from a Spin SDK
+    /// secret by the caller) and an optional auth token.
+    pub fn connect_from_url(sync_url: &str, auth_token: Option<String>) -> Result<Self> {
+        let mut inner = super::reqwest::native::Client::from_url(&url::Url::parse(sync_url)?)?;
+        if let Some(token) = auth_token {
+            inner.set_auth_token(token);
+        }
+        Ok(Self { inner })
+    }
+}
+
+#[async_trait(?Send)]
+impl DatabaseClient for Client {
+    async fn batch(
+        &self,
+        stmts: impl IntoIterator<Item = impl Into<Statement>>,
+    ) -> Result<Vec<QueryResult>> {
+        self.inner.batch(stmts).await
+    }
+
+    fn pin_session(&self) {
+        self.inner.pin_session();
+    }
+
+    fn unpin_session(&self) {
+        self.inner.unpin_session();
+    }
+}
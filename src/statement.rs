@@ -0,0 +1,48 @@
+//! A single SQL statement plus its bound parameters, and the wire format the
+//! HTTP backends post to a `sqld`/libSQL endpoint.
+
+use super::Value;
+
+/// A SQL statement and the parameters it was built with.
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub(crate) sql: String,
+    pub(crate) params: Vec<Value>,
+}
+
+impl Statement {
+    /// A statement with no bound parameters.
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            params: Vec::new(),
+        }
+    }
+
+    /// A statement with `?`-style placeholders bound to `params`, in order.
+    pub fn with_params(sql: impl Into<String>, params: Vec<Value>) -> Self {
+        Self {
+            sql: sql.into(),
+            params,
+        }
+    }
+}
+
+impl From<&str> for Statement {
+    fn from(sql: &str) -> Self {
+        Statement::new(sql)
+    }
+}
+
+impl From<String> for Statement {
+    fn from(sql: String) -> Self {
+        Statement::new(sql)
+    }
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = serde_json::json!({ "q": self.sql, "params": self.params });
+        write!(f, "{value}")
+    }
+}
@@ -0,0 +1,393 @@
+//! A streaming row API, analogous to `tokio-postgres`'s `RowStream`: rows are
+//! yielded as they're parsed instead of only after the whole result array has
+//! been buffered and deserialized.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use anyhow::Result;
+use futures::Stream;
+
+use super::Row;
+
+/// A stream of [`Row`]s produced by [`DatabaseClient::query_stream`].
+///
+/// Backends that can parse their response body incrementally (e.g. the
+/// `reqwest` backend, chunk-by-chunk off the wire) should construct this
+/// from that incremental parser so rows become available before the full
+/// response has arrived. The default implementation in
+/// [`DatabaseClient::query_stream`] instead parses eagerly and replays the
+/// already-buffered rows, which keeps the API usable on backends that have
+/// no cheaper way to produce it, at the cost of not saving any memory there.
+pub struct RowStream {
+    inner: Pin<Box<dyn Stream<Item = Result<Row>>>>,
+}
+
+impl RowStream {
+    /// Wraps any `Result<Row>` stream so it can be returned as a `RowStream`.
+    pub fn new(inner: impl Stream<Item = Result<Row>> + 'static) -> Self {
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Builds a `RowStream` that replays an already-buffered vector of rows.
+    pub fn from_buffered(rows: Vec<Row>) -> Self {
+        Self::new(futures::stream::iter(rows.into_iter().map(Ok)))
+    }
+}
+
+impl Stream for RowStream {
+    type Item = Result<Row>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Incrementally parses the elements of a `"rows": [...]` JSON array out of
+/// an HTTP response body as its bytes arrive, instead of waiting for the
+/// whole body and deserializing it in one shot. Used by backends (the
+/// `reqwest` ones) that can get at the response as a byte stream.
+///
+/// This assumes the wire format already used for `batch()`/`execute()`:
+/// each statement's result is a JSON object containing a `"rows"` array,
+/// whose elements are parsed one at a time as soon as their closing
+/// bracket/brace has arrived, so memory use tracks one row at a time
+/// instead of the whole result set.
+pub(crate) fn parse_rows_stream<S, E>(byte_stream: S) -> impl Stream<Item = Result<serde_json::Value>>
+where
+    S: Stream<Item = std::result::Result<bytes::Bytes, E>> + 'static,
+    E: std::fmt::Display + 'static,
+{
+    use futures::StreamExt;
+
+    struct State<S> {
+        byte_stream: Pin<Box<S>>,
+        buf: Vec<u8>,
+        pos: usize,
+        array_opened: bool,
+        rows_found: bool,
+    }
+
+    futures::stream::unfold(
+        State {
+            byte_stream: Box::pin(byte_stream),
+            buf: Vec::new(),
+            pos: 0,
+            array_opened: false,
+            rows_found: false,
+        },
+        |mut state| async move {
+            loop {
+                if !state.rows_found {
+                    match find_rows_array_start(&state.buf) {
+                        Some(idx) => {
+                            state.pos = idx;
+                            state.rows_found = true;
+                        }
+                        None => {
+                            // Haven't seen the `"rows"` key yet - read more and,
+                            // once it's clearly not coming (stream ended), stop.
+                            match state.byte_stream.next().await {
+                                Some(Ok(chunk)) => {
+                                    state.buf.extend_from_slice(&chunk);
+                                    continue;
+                                }
+                                Some(Err(e)) => return Some((Err(anyhow::anyhow!("{e}")), state)),
+                                None => return None,
+                            }
+                        }
+                    }
+                }
+
+                if let Some((start, end)) =
+                    next_array_element(&state.buf, &mut state.pos, &mut state.array_opened)
+                {
+                    state.pos = end;
+                    let slice = state.buf[start..end].to_vec();
+                    return Some((
+                        serde_json::from_slice::<serde_json::Value>(&slice)
+                            .map_err(|e| anyhow::anyhow!(e)),
+                        state,
+                    ));
+                }
+
+                if state.array_opened && array_is_closed(&state.buf, state.pos) {
+                    return None;
+                }
+
+                match state.byte_stream.next().await {
+                    Some(Ok(chunk)) => state.buf.extend_from_slice(&chunk),
+                    Some(Err(e)) => return Some((Err(anyhow::anyhow!("{e}")), state)),
+                    None => return None,
+                }
+            }
+        },
+    )
+}
+
+/// Finds where the value of the first `"rows"` *key* starts (at whatever
+/// nesting depth, e.g. inside the batch response's outer array), by
+/// actually walking the JSON structure (tracking object/array nesting and
+/// string contents) rather than searching for the literal bytes `"rows"`.
+/// A raw substring search would also match `"rows"` appearing as a string
+/// value - e.g. a column named `rows` inside the `"columns"` array - and
+/// misparse from there; this only matches it in key position, directly
+/// inside an object. Returns `None` if the buffer doesn't yet contain
+/// enough to tell (more bytes are needed) or no such key exists at all.
+fn find_rows_array_start(buf: &[u8]) -> Option<usize> {
+    // `true` = currently inside a JSON object (entries are `"key": value`),
+    // `false` = currently inside a JSON array (entries are bare values).
+    let mut stack: Vec<bool> = Vec::new();
+    let mut need_key = false;
+    let mut i = 0usize;
+
+    loop {
+        i = skip_ws(buf, i);
+        let in_object = matches!(stack.last(), Some(true));
+
+        if in_object && need_key {
+            if i >= buf.len() || buf[i] != b'"' {
+                return None;
+            }
+            let key_end = string_end(buf, i)?;
+            let key = &buf[i + 1..key_end - 1];
+            i = skip_ws(buf, key_end);
+            if i >= buf.len() || buf[i] != b':' {
+                return None;
+            }
+            i = skip_ws(buf, i + 1);
+            if key == b"rows".as_slice() {
+                return Some(i);
+            }
+            need_key = false;
+            continue;
+        }
+
+        if i >= buf.len() {
+            return None;
+        }
+
+        match buf[i] {
+            b'{' => {
+                stack.push(true);
+                need_key = true;
+                i += 1;
+            }
+            b'[' => {
+                stack.push(false);
+                need_key = false;
+                i += 1;
+            }
+            b'}' | b']' => {
+                stack.pop();
+                i = skip_ws(buf, i + 1);
+                need_key = consume_separator(buf, &mut i, &stack);
+            }
+            b'"' => {
+                i = string_end(buf, i)?;
+                i = skip_ws(buf, i);
+                need_key = consume_separator(buf, &mut i, &stack);
+            }
+            _ => {
+                // A bare literal: number, `true`, `false`, or `null`.
+                while i < buf.len() && !matches!(buf[i], b',' | b'}' | b']') {
+                    i += 1;
+                }
+                if i >= buf.len() {
+                    // Could just be a truncated literal - ask for more bytes.
+                    return None;
+                }
+                need_key = consume_separator(buf, &mut i, &stack);
+            }
+        }
+    }
+}
+
+/// After finishing one object/array entry at `*i`, consumes a following `,`
+/// if present and reports whether the next token should be parsed as an
+/// object key (i.e. we're directly inside an object, about to start a new
+/// entry).
+fn consume_separator(buf: &[u8], i: &mut usize, stack: &[bool]) -> bool {
+    if *i < buf.len() && buf[*i] == b',' {
+        *i = skip_ws(buf, *i + 1);
+        matches!(stack.last(), Some(true))
+    } else {
+        false
+    }
+}
+
+/// Returns the index just past the closing quote of the string starting at
+/// `buf[start]` (which must be `"`), or `None` if it isn't closed yet.
+fn string_end(buf: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    let mut escape = false;
+    while i < buf.len() {
+        let c = buf[i];
+        if escape {
+            escape = false;
+        } else if c == b'\\' {
+            escape = true;
+        } else if c == b'"' {
+            return Some(i + 1);
+        }
+        i += 1;
+    }
+    None
+}
+
+fn skip_ws(buf: &[u8], mut i: usize) -> usize {
+    while i < buf.len() && (buf[i] as char).is_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+fn array_is_closed(buf: &[u8], pos: usize) -> bool {
+    let mut i = skip_ws(buf, pos);
+    if i < buf.len() && buf[i] == b',' {
+        i = skip_ws(buf, i + 1);
+    }
+    i < buf.len() && buf[i] == b']'
+}
+
+/// Finds the next complete element of a JSON array starting at `pos`,
+/// returning its byte range. `pos` should point just past the `"rows":`
+/// key the first time this is called; `array_opened` tracks whether we've
+/// already consumed the array's opening `[`, and `pos` is moved past that
+/// `[` when it is consumed. Returns `None` if the buffer doesn't yet
+/// contain a complete next element (more bytes are needed).
+fn next_array_element(buf: &[u8], pos: &mut usize, array_opened: &mut bool) -> Option<(usize, usize)> {
+    let mut i = *pos;
+    if !*array_opened {
+        i = skip_ws(buf, i);
+        if i >= buf.len() || buf[i] != b'[' {
+            return None;
+        }
+        i += 1;
+        *array_opened = true;
+        *pos = i;
+    }
+    i = skip_ws(buf, i);
+    if i < buf.len() && buf[i] == b',' {
+        i += 1;
+        i = skip_ws(buf, i);
+    }
+    if i >= buf.len() || buf[i] == b']' {
+        return None;
+    }
+
+    let start = i;
+    let first = buf[start];
+    if first == b'{' || first == b'[' {
+        let mut depth = 0i32;
+        let mut in_string = false;
+        let mut escape = false;
+        while i < buf.len() {
+            let c = buf[i];
+            if in_string {
+                if escape {
+                    escape = false;
+                } else if c == b'\\' {
+                    escape = true;
+                } else if c == b'"' {
+                    in_string = false;
+                }
+            } else {
+                match c {
+                    b'"' => in_string = true,
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((start, i + 1));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+        None
+    } else if first == b'"' {
+        let mut j = i + 1;
+        let mut escape = false;
+        while j < buf.len() {
+            let c = buf[j];
+            if escape {
+                escape = false;
+            } else if c == b'\\' {
+                escape = true;
+            } else if c == b'"' {
+                return Some((start, j + 1));
+            }
+            j += 1;
+        }
+        None
+    } else {
+        let mut j = i;
+        while j < buf.len() {
+            let c = buf[j];
+            if c == b',' || c == b']' || (c as char).is_whitespace() {
+                return Some((start, j));
+            }
+            j += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn from_buffered_yields_rows_in_order() {
+        let rows = vec![Row::default(), Row::default()];
+        let count = rows.len();
+        let stream = RowStream::from_buffered(rows);
+        let collected: Vec<_> = stream.collect().await;
+        assert_eq!(collected.len(), count);
+        assert!(collected.iter().all(|r| r.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn parse_rows_stream_yields_each_row_incrementally() {
+        let body = br#"{"columns":["id"],"rows":[[1],[2],[3]]}"#.to_vec();
+        // Split across several chunks to exercise incremental parsing.
+        let chunks: Vec<std::result::Result<bytes::Bytes, std::convert::Infallible>> = body
+            .chunks(5)
+            .map(|c| Ok(bytes::Bytes::copy_from_slice(c)))
+            .collect();
+        let byte_stream = futures::stream::iter(chunks);
+
+        let values: Vec<_> = parse_rows_stream(byte_stream).collect().await;
+        let values: Vec<serde_json::Value> = values.into_iter().collect::<Result<_>>().unwrap();
+        assert_eq!(
+            values,
+            vec![
+                serde_json::json!([1]),
+                serde_json::json!([2]),
+                serde_json::json!([3]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn parse_rows_stream_ignores_a_column_literally_named_rows() {
+        // A naive substring search for `"rows"` would match inside the
+        // `"columns"` array below and misparse from there; the real `"rows"`
+        // key only appears afterwards, in object-key position.
+        let body = br#"{"columns":["rows","id"],"rows":[[1,2]]}"#.to_vec();
+        let byte_stream = futures::stream::iter(vec![Ok::<_, std::convert::Infallible>(
+            bytes::Bytes::copy_from_slice(&body),
+        )]);
+
+        let values: Vec<_> = parse_rows_stream(byte_stream).collect().await;
+        let values: Vec<serde_json::Value> = values.into_iter().collect::<Result<_>>().unwrap();
+        assert_eq!(values, vec![serde_json::json!([1, 2])]);
+    }
+}
@@ -0,0 +1,215 @@
+//! Interactive transaction handles: a `Transaction` borrows its client and
+//! lets the caller inspect intermediate `QueryResult`s between statements
+//! instead of submitting a whole batch up front.
+//!
+//! For stateless HTTP backends (`reqwest`, `workers`, `spin`), where every
+//! call to `batch()` would otherwise be an independent request, opening a
+//! `Transaction` pins the client's session (see
+//! [`DatabaseClient::pin_session`]) so `BEGIN`, the statements issued
+//! through the guard, and the final `COMMIT`/`ROLLBACK` all land on the same
+//! server-side connection.
+
+use anyhow::Result;
+
+use super::{DatabaseClient, QueryResult, Statement};
+
+/// A guard representing an in-progress transaction (or, when nested, a
+/// savepoint within one). Obtained from [`DatabaseClient::begin`].
+///
+/// `Transaction` is `#[must_use]`: binding it to `_` or otherwise dropping
+/// it without calling [`commit`](Transaction::commit) or
+/// [`rollback`](Transaction::rollback) is almost always a bug. We cannot fire
+/// an async `ROLLBACK` from a synchronous `Drop` impl the way a destructor
+/// with a reachable runtime could, so a dropped-without-resolving
+/// `Transaction` leaves the transaction/savepoint open server-side; `drop`
+/// only releases the client-side session pin so the client itself doesn't
+/// keep reusing a connection it believes is mid-transaction. Always call
+/// `commit()` or `rollback()` explicitly - the `#[must_use]` above is the
+/// compile-time nudge for that.
+#[must_use = "a Transaction left unresolved stays open server-side - call commit() or rollback()"]
+pub struct Transaction<'c, C: DatabaseClient> {
+    client: &'c C,
+    depth: u32,
+    done: bool,
+}
+
+impl<'c, C: DatabaseClient> Transaction<'c, C> {
+    pub(crate) async fn new(client: &'c C, depth: u32) -> Result<Self> {
+        let stmt = if depth == 0 {
+            Statement::new("BEGIN")
+        } else {
+            Statement::new(format!("SAVEPOINT sp{depth}"))
+        };
+        // Pin the session *before* issuing BEGIN: a stateless HTTP backend
+        // only learns the session id from BEGIN's own response, so it must
+        // already be in pinned mode to capture it. If BEGIN then fails, undo
+        // the pin immediately rather than leaving the session pinned with no
+        // Transaction around to eventually unpin it.
+        if depth == 0 {
+            client.pin_session();
+        }
+        if let Err(e) = client.execute(stmt).await {
+            if depth == 0 {
+                client.unpin_session();
+            }
+            return Err(e);
+        }
+        Ok(Self {
+            client,
+            depth,
+            done: false,
+        })
+    }
+
+    /// Executes a single statement within this transaction.
+    pub async fn execute(&self, stmt: impl Into<Statement>) -> Result<QueryResult> {
+        self.client.execute(stmt).await
+    }
+
+    /// Executes a batch of statements within this transaction.
+    pub async fn batch(
+        &self,
+        stmts: impl IntoIterator<Item = impl Into<Statement>>,
+    ) -> Result<Vec<QueryResult>> {
+        self.client.batch(stmts).await
+    }
+
+    /// Opens a nested transaction (a savepoint) within this one.
+    pub async fn begin(&self) -> Result<Transaction<'_, C>> {
+        Transaction::new(self.client, self.depth + 1).await
+    }
+
+    /// Commits this transaction (or releases this savepoint).
+    pub async fn commit(mut self) -> Result<()> {
+        let stmt = if self.depth == 0 {
+            Statement::new("COMMIT")
+        } else {
+            Statement::new(format!("RELEASE sp{}", self.depth))
+        };
+        self.client.execute(stmt).await?;
+        self.done = true;
+        if self.depth == 0 {
+            self.client.unpin_session();
+        }
+        Ok(())
+    }
+
+    /// Rolls back this transaction (or to this savepoint).
+    pub async fn rollback(mut self) -> Result<()> {
+        let stmt = if self.depth == 0 {
+            Statement::new("ROLLBACK")
+        } else {
+            Statement::new(format!("ROLLBACK TO sp{}", self.depth))
+        };
+        self.client.execute(stmt).await?;
+        self.done = true;
+        if self.depth == 0 {
+            self.client.unpin_session();
+        }
+        Ok(())
+    }
+}
+
+impl<'c, C: DatabaseClient> Drop for Transaction<'c, C> {
+    fn drop(&mut self) {
+        // No logging here: this crate has no logging facade dependency, and
+        // unconditionally writing to stderr would be rude to downstream
+        // consumers. The `#[must_use]` on the struct already gives callers a
+        // compile-time nudge; all `drop` does is release the client-side
+        // pin so the transaction/savepoint left open server-side doesn't
+        // also strand the session pinned on the client.
+        if !self.done && self.depth == 0 {
+            self.client.unpin_session();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use async_trait::async_trait;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MockClient {
+        issued: RefCell<Vec<String>>,
+        pinned: Cell<bool>,
+        fail_next: Cell<bool>,
+    }
+
+    #[async_trait(?Send)]
+    impl DatabaseClient for MockClient {
+        async fn batch(
+            &self,
+            stmts: impl IntoIterator<Item = impl Into<Statement>>,
+        ) -> Result<Vec<QueryResult>> {
+            if self.fail_next.replace(false) {
+                anyhow::bail!("mock failure");
+            }
+            let mut count = 0;
+            for stmt in stmts {
+                self.issued.borrow_mut().push(format!("{}", stmt.into()));
+                count += 1;
+            }
+            Ok((0..count).map(|_| QueryResult::default()).collect())
+        }
+
+        fn pin_session(&self) {
+            self.pinned.set(true);
+        }
+
+        fn unpin_session(&self) {
+            self.pinned.set(false);
+        }
+    }
+
+    #[tokio::test]
+    async fn begin_commit_issues_begin_then_commit_and_pins_session() {
+        let client = MockClient::default();
+        let tx = client.begin().await.unwrap();
+        assert!(client.pinned.get());
+        tx.commit().await.unwrap();
+        assert!(!client.pinned.get());
+        let issued = client.issued.borrow();
+        assert_eq!(issued.len(), 2);
+        assert!(issued[0].contains("BEGIN"));
+        assert!(issued[1].contains("COMMIT"));
+    }
+
+    #[tokio::test]
+    async fn nested_begin_uses_savepoints_and_keeps_outer_session_pinned() {
+        let client = MockClient::default();
+        let outer = client.begin().await.unwrap();
+        let inner = outer.begin().await.unwrap();
+        inner.rollback().await.unwrap();
+        assert!(client.pinned.get());
+        outer.commit().await.unwrap();
+        assert!(!client.pinned.get());
+        let issued = client.issued.borrow();
+        assert_eq!(issued.len(), 4);
+        assert!(issued[0].contains("BEGIN"));
+        assert!(issued[1].contains("SAVEPOINT sp1"));
+        assert!(issued[2].contains("ROLLBACK TO sp1"));
+        assert!(issued[3].contains("COMMIT"));
+    }
+
+    #[tokio::test]
+    async fn failed_begin_does_not_leave_the_session_pinned() {
+        let client = MockClient::default();
+        client.fail_next.set(true);
+        assert!(client.begin().await.is_err());
+        assert!(!client.pinned.get());
+    }
+
+    #[tokio::test]
+    async fn dropping_without_resolving_unpins_outer_session() {
+        let client = MockClient::default();
+        {
+            let _tx = client.begin().await.unwrap();
+            assert!(client.pinned.get());
+        }
+        assert!(!client.pinned.get());
+    }
+}
@@ -0,0 +1,53 @@
+//! Cloudflare Workers backend. Workers have no process environment, so the
+//! database URL/token have to come from the `worker::RouteContext` bound to
+//! the incoming request instead of `std::env` - see [`Client::connect_from_ctx`].
+
+use async_trait::async_trait;
+
+use anyhow::Result;
+
+use super::{DatabaseClient, QueryResult, Statement};
+
+/// A client for use inside a Cloudflare Worker, backed by the `wasm`
+/// `reqwest` client under the hood.
+pub struct Client {
+    inner: super::reqwest::wasm::Client,
+}
+
+impl Client {
+    /// Builds a client from the `LIBSQL_CLIENT_URL`/`LIBSQL_CLIENT_AUTH_TOKEN`
+    /// secrets or vars bound to `ctx`.
+    pub fn connect_from_ctx(ctx: &worker::RouteContext<()>) -> Result<Self> {
+        let url = ctx
+            .secret("LIBSQL_CLIENT_URL")
+            .or_else(|_| ctx.var("LIBSQL_CLIENT_URL"))
+            .map_err(|e| anyhow::anyhow!("{e}"))?
+            .to_string();
+        let mut inner = super::reqwest::wasm::Client::from_url(&url::Url::parse(&url)?)?;
+        if let Ok(token) = ctx
+            .secret("LIBSQL_CLIENT_AUTH_TOKEN")
+            .or_else(|_| ctx.var("LIBSQL_CLIENT_AUTH_TOKEN"))
+        {
+            inner.set_auth_token(token.to_string());
+        }
+        Ok(Self { inner })
+    }
+}
+
+#[async_trait(?Send)]
+impl DatabaseClient for Client {
+    async fn batch(
+        &self,
+        stmts: impl IntoIterator<Item = impl Into<Statement>>,
+    ) -> Result<Vec<QueryResult>> {
+        self.inner.batch(stmts).await
+    }
+
+    fn pin_session(&self) {
+        self.inner.pin_session();
+    }
+
+    fn unpin_session(&self) {
+        self.inner.unpin_session();
+    }
+}